@@ -0,0 +1,166 @@
+use crate::error::FlywayNaimngCheckerError;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Name of the lockfile, sat alongside the migrations, that maps each
+/// migration file name to the SHA-256 of its contents at the time it was
+/// recorded.
+pub const LOCKFILE_NAME: &str = ".flyway-checksums.toml";
+
+fn hash_file(path: &Path) -> Result<String, FlywayNaimngCheckerError> {
+    let contents = fs::read(path).map_err(|source| FlywayNaimngCheckerError::DirectoryReadError {
+        path: path.display().to_string(),
+        message: source.to_string(),
+    })?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(&contents);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn lockfile_path(dir: &Path) -> std::path::PathBuf {
+    dir.join(LOCKFILE_NAME)
+}
+
+fn read_lockfile(dir: &Path) -> Result<BTreeMap<String, String>, FlywayNaimngCheckerError> {
+    let path = lockfile_path(dir);
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    let contents = fs::read_to_string(&path).map_err(|source| {
+        FlywayNaimngCheckerError::DirectoryReadError {
+            path: path.display().to_string(),
+            message: source.to_string(),
+        }
+    })?;
+
+    let value: toml::Value = contents
+        .parse()
+        .map_err(|source: toml::de::Error| FlywayNaimngCheckerError::LockfileError {
+            path: path.display().to_string(),
+            message: source.to_string(),
+        })?;
+
+    let table = value
+        .as_table()
+        .ok_or_else(|| FlywayNaimngCheckerError::LockfileError {
+            path: path.display().to_string(),
+            message: "expected a table of file name to checksum".to_string(),
+        })?;
+
+    let mut checksums = BTreeMap::new();
+    for (file, checksum) in table {
+        let checksum = checksum
+            .as_str()
+            .ok_or_else(|| FlywayNaimngCheckerError::LockfileError {
+                path: path.display().to_string(),
+                message: format!("checksum for '{}' is not a string", file),
+            })?;
+        checksums.insert(file.clone(), checksum.to_string());
+    }
+
+    Ok(checksums)
+}
+
+fn write_lockfile(
+    dir: &Path,
+    checksums: &BTreeMap<String, String>,
+) -> Result<(), FlywayNaimngCheckerError> {
+    let path = lockfile_path(dir);
+
+    let contents = toml::to_string_pretty(checksums).map_err(|source| {
+        FlywayNaimngCheckerError::LockfileError {
+            path: path.display().to_string(),
+            message: source.to_string(),
+        }
+    })?;
+
+    fs::write(&path, contents).map_err(|source| FlywayNaimngCheckerError::LockfileError {
+        path: path.display().to_string(),
+        message: source.to_string(),
+    })
+}
+
+/// Hash every file in `files` (resolved under `dir`) and persist the result
+/// to the lockfile, creating or updating entries as needed.
+pub fn record_checksums(dir: &Path, files: &[String]) -> Result<(), FlywayNaimngCheckerError> {
+    let mut checksums = read_lockfile(dir)?;
+
+    for file_name in files {
+        let checksum = hash_file(&dir.join(file_name))?;
+        checksums.insert(file_name.clone(), checksum);
+    }
+
+    write_lockfile(dir, &checksums)
+}
+
+/// Re-hash every file in `files` that has a recorded checksum and fail as
+/// soon as one no longer matches, catching migrations edited after they were
+/// already applied downstream.
+pub fn verify_checksums(dir: &Path, files: &[String]) -> Result<(), FlywayNaimngCheckerError> {
+    let checksums = read_lockfile(dir)?;
+
+    for file_name in files {
+        if let Some(expected) = checksums.get(file_name) {
+            let found = hash_file(&dir.join(file_name))?;
+            if &found != expected {
+                return Err(FlywayNaimngCheckerError::ChecksumMismatch {
+                    file: file_name.clone(),
+                    expected: expected.clone(),
+                    found,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    fn temp_dir() -> std::path::PathBuf {
+        let id = COUNTER.fetch_add(1, Ordering::SeqCst);
+        let dir = std::env::temp_dir().join(format!("flyway-naming-checker-checksum-test-{}", id));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_record_then_verify_checksums() {
+        let dir = temp_dir();
+        let file = "V1__init.sql".to_string();
+        fs::write(dir.join(&file), "create table foo();").unwrap();
+
+        record_checksums(&dir, std::slice::from_ref(&file)).unwrap();
+        assert_eq!(verify_checksums(&dir, &[file]), Ok(()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_verify_checksums_detects_drift() {
+        let dir = temp_dir();
+        let file = "V1__init.sql".to_string();
+        fs::write(dir.join(&file), "create table foo();").unwrap();
+
+        record_checksums(&dir, std::slice::from_ref(&file)).unwrap();
+        fs::write(dir.join(&file), "drop table foo;").unwrap();
+
+        match verify_checksums(&dir, std::slice::from_ref(&file)) {
+            Err(FlywayNaimngCheckerError::ChecksumMismatch { file: f, .. }) => {
+                assert_eq!(f, file)
+            }
+            other => panic!("expected ChecksumMismatch, got {:?}", other),
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
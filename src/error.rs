@@ -0,0 +1,137 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlywayNaimngCheckerError {
+    FlywayNamingPrefixError {
+        file: String,
+        expected: String,
+        found: String,
+    },
+    FlywayNamingSufixError {
+        file: String,
+        expected: String,
+        found: String,
+    },
+    FlywayNamingSeparatorError {
+        file: String,
+    },
+    FlywayNamingVersionError {
+        file: String,
+        prefix: String,
+        found: String,
+    },
+    FlywayNamingCanNotFindPrefix {
+        file: String,
+    },
+    FlywayNamingCanNotFindVersion {
+        file: String,
+    },
+    FlywayNamingVersionComponentError {
+        file: String,
+        segment: String,
+    },
+    DuplicateVersion {
+        file: String,
+    },
+    SkippedVersion {
+        file: String,
+    },
+    DirectoryReadError {
+        path: String,
+        message: String,
+    },
+    ChecksumMismatch {
+        file: String,
+        expected: String,
+        found: String,
+    },
+    OrphanUndoMigration {
+        file: String,
+    },
+    RepeatableMissingDescription {
+        file: String,
+    },
+    RepeatableHasVersion {
+        file: String,
+    },
+    LockfileError {
+        path: String,
+        message: String,
+    },
+}
+
+impl fmt::Display for FlywayNaimngCheckerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlywayNaimngCheckerError::FlywayNamingPrefixError {
+                file,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: expected prefix '{}', found '{}'",
+                file, expected, found
+            ),
+            FlywayNaimngCheckerError::FlywayNamingSufixError {
+                file,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: expected suffix '{}', found '{}'",
+                file, expected, found
+            ),
+            FlywayNaimngCheckerError::FlywayNamingSeparatorError { file } => {
+                write!(f, "{}: expected exactly one separator between version and description", file)
+            }
+            FlywayNaimngCheckerError::FlywayNamingVersionError {
+                file,
+                prefix,
+                found,
+            } => write!(
+                f,
+                "{}: expected a digit after prefix '{}', found '{}'",
+                file, prefix, found
+            ),
+            FlywayNaimngCheckerError::FlywayNamingCanNotFindPrefix { file } => {
+                write!(f, "{}: could not find a naming prefix", file)
+            }
+            FlywayNaimngCheckerError::FlywayNamingCanNotFindVersion { file } => {
+                write!(f, "{}: could not find a version", file)
+            }
+            FlywayNaimngCheckerError::FlywayNamingVersionComponentError { file, segment } => {
+                write!(f, "{}: version component '{}' is not numeric", file, segment)
+            }
+            FlywayNaimngCheckerError::DuplicateVersion { file } => {
+                write!(f, "{}: duplicate version", file)
+            }
+            FlywayNaimngCheckerError::SkippedVersion { file } => write!(f, "{}", file),
+            FlywayNaimngCheckerError::DirectoryReadError { path, message } => {
+                write!(f, "{}: failed to read: {}", path, message)
+            }
+            FlywayNaimngCheckerError::ChecksumMismatch {
+                file,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: checksum drift detected, expected '{}', found '{}'",
+                file, expected, found
+            ),
+            FlywayNaimngCheckerError::OrphanUndoMigration { file } => {
+                write!(f, "{}: no matching V migration for this undo migration", file)
+            }
+            FlywayNaimngCheckerError::RepeatableMissingDescription { file } => {
+                write!(f, "{}: repeatable migration is missing a description", file)
+            }
+            FlywayNaimngCheckerError::RepeatableHasVersion { file } => {
+                write!(f, "{}: repeatable migrations must not carry a version", file)
+            }
+            FlywayNaimngCheckerError::LockfileError { path, message } => {
+                write!(f, "{}: {}", path, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FlywayNaimngCheckerError {}
@@ -0,0 +1,4 @@
+pub mod checksum;
+pub mod error;
+pub mod naming_checker;
+pub mod report;
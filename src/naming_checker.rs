@@ -1,25 +1,66 @@
 use crate::error::FlywayNaimngCheckerError;
+use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
-pub fn is_valid_prefix(file_name: &str) -> Result<(), FlywayNaimngCheckerError> {
+/// The naming scheme a set of migration files is checked against: which
+/// leading characters count as a valid prefix, the separator between the
+/// version and the description, and the accepted file suffixes. Mirrors the
+/// `sqlSchemePrefix` / `sqlMigrationSeparator` / `sqlMigrationSuffixes`
+/// settings Flyway itself exposes in `flyway.conf`.
+///
+/// `version_prefix`, `undo_prefix`, and `repeatable_prefix` carry the role
+/// each prefix plays, rather than leaving it to be inferred from position in
+/// `prefixes` — a scheme with fewer than three prefixes (e.g. a team that
+/// only uses versioned migrations) still has unambiguous roles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamingConfig {
+    pub prefixes: Vec<char>,
+    pub separator: String,
+    pub suffixes: Vec<String>,
+    pub version_prefix: char,
+    pub undo_prefix: Option<char>,
+    pub repeatable_prefix: Option<char>,
+}
+
+impl Default for NamingConfig {
+    fn default() -> Self {
+        NamingConfig {
+            prefixes: vec!['V', 'U', 'R'],
+            separator: "__".to_string(),
+            suffixes: vec!["sql".to_string()],
+            version_prefix: 'V',
+            undo_prefix: Some('U'),
+            repeatable_prefix: Some('R'),
+        }
+    }
+}
+
+pub fn is_valid_prefix(
+    file_name: &str,
+    config: &NamingConfig,
+) -> Result<(), FlywayNaimngCheckerError> {
     let first_char = file_name.chars().next();
 
     match first_char {
         Some(prefix) => {
-            if !(prefix == 'V' || prefix == 'U' || prefix == 'R') {
-                let mut expected_prefix = "V";
+            if !config.prefixes.contains(&prefix) {
+                let is_lowercase_alternate = prefix != config.version_prefix.to_ascii_lowercase()
+                    && [config.undo_prefix, config.repeatable_prefix]
+                        .into_iter()
+                        .flatten()
+                        .any(|p| p.to_ascii_lowercase() == prefix);
 
-                if prefix == 'u' {
-                    expected_prefix = "u";
-                }
-
-                if prefix == 'r' {
-                    expected_prefix = "r";
-                }
+                let expected_prefix = if is_lowercase_alternate {
+                    prefix.to_string()
+                } else {
+                    config.version_prefix.to_string()
+                };
 
                 Err(FlywayNaimngCheckerError::FlywayNamingPrefixError {
                     file: file_name.to_string(),
-                    expected: expected_prefix.to_string(),
+                    expected: expected_prefix,
                     found: prefix.to_string(),
                 })
             } else {
@@ -32,13 +73,20 @@ pub fn is_valid_prefix(file_name: &str) -> Result<(), FlywayNaimngCheckerError>
     }
 }
 
-pub fn is_valid_suffix(file_name: &str) -> Result<(), FlywayNaimngCheckerError> {
+pub fn is_valid_suffix(
+    file_name: &str,
+    config: &NamingConfig,
+) -> Result<(), FlywayNaimngCheckerError> {
     if let Some(dot_index) = file_name.rfind('.') {
         let suffix = &file_name[dot_index + 1..];
-        if suffix != "sql" {
+        if !config.suffixes.iter().any(|expected| expected == suffix) {
             return Err(FlywayNaimngCheckerError::FlywayNamingSufixError {
                 file: file_name.to_string(),
-                expected: ".sql".to_owned(),
+                expected: config
+                    .suffixes
+                    .first()
+                    .map(|s| format!(".{}", s))
+                    .unwrap_or_else(|| ".sql".to_string()),
                 found: suffix.to_owned(),
             });
         }
@@ -46,8 +94,11 @@ pub fn is_valid_suffix(file_name: &str) -> Result<(), FlywayNaimngCheckerError>
     Ok(())
 }
 
-pub fn is_valid_sepeartor(file_name: &str) -> Result<(), FlywayNaimngCheckerError> {
-    let parts: Vec<&str> = file_name.split("__").collect();
+pub fn is_valid_sepeartor(
+    file_name: &str,
+    config: &NamingConfig,
+) -> Result<(), FlywayNaimngCheckerError> {
+    let parts: Vec<&str> = file_name.split(config.separator.as_str()).collect();
 
     if parts.len() != 2 {
         Err(FlywayNaimngCheckerError::FlywayNamingSeparatorError {
@@ -58,46 +109,62 @@ pub fn is_valid_sepeartor(file_name: &str) -> Result<(), FlywayNaimngCheckerErro
     }
 }
 
-pub fn is_valid_version(file_name: &str) -> Result<(), FlywayNaimngCheckerError> {
+pub fn is_valid_version(
+    file_name: &str,
+    config: &NamingConfig,
+) -> Result<(), FlywayNaimngCheckerError> {
     match file_name.chars().next() {
-        Some(first_char) => match first_char.to_owned() {
-            'V' | 'U' => {
-                let second_char = match file_name.chars().nth(1) {
-                    Some(c) => c,
-                    None => {
-                        return Err(FlywayNaimngCheckerError::FlywayNamingCanNotFindVersion {
-                            file: file_name.to_string(),
-                        })
-                    }
-                };
-                if second_char.is_ascii_digit() {
-                    Ok(())
-                } else {
-                    Err(FlywayNaimngCheckerError::FlywayNamingVersionError {
+        Some(first_char) => {
+            if !config.prefixes.contains(&first_char) {
+                return Err(FlywayNaimngCheckerError::FlywayNamingPrefixError {
+                    file: file_name.to_string(),
+                    expected: config.version_prefix.to_string(),
+                    found: first_char.to_string(),
+                });
+            }
+
+            // The repeatable prefix (`R` by default), if configured, carries
+            // no version at all.
+            if config.repeatable_prefix == Some(first_char) {
+                return Ok(());
+            }
+
+            let second_char = match file_name.chars().nth(1) {
+                Some(c) => c,
+                None => {
+                    return Err(FlywayNaimngCheckerError::FlywayNamingCanNotFindVersion {
                         file: file_name.to_string(),
-                        prefix: first_char.to_string(),
-                        found: second_char.to_string(),
                     })
                 }
+            };
+            if second_char.is_ascii_digit() {
+                Ok(())
+            } else {
+                Err(FlywayNaimngCheckerError::FlywayNamingVersionError {
+                    file: file_name.to_string(),
+                    prefix: first_char.to_string(),
+                    found: second_char.to_string(),
+                })
             }
-            'R' => Ok(()),
-            _ => Err(FlywayNaimngCheckerError::FlywayNamingPrefixError {
-                file: file_name.to_string(),
-                expected: "V".to_string(),
-                found: first_char.to_string(),
-            }),
-        },
+        }
         None => Err(FlywayNaimngCheckerError::FlywayNamingCanNotFindPrefix {
             file: file_name.to_string(),
         }),
     }
 }
 
-pub fn check_for_duplicate_versions(file_names: &[String]) -> Result<(), FlywayNaimngCheckerError> {
+pub fn check_for_duplicate_versions(
+    file_names: &[String],
+    config: &NamingConfig,
+) -> Result<(), FlywayNaimngCheckerError> {
     let mut versions = HashSet::new();
 
     for file_name in file_names {
-        if let Some(version) = extract_version(file_name) {
+        // An undo migration shares its version with the migration it undoes.
+        if config.undo_prefix.is_some_and(|p| file_name.starts_with(p)) {
+            continue;
+        }
+        if let Some(version) = extract_version(file_name, config)? {
             if !versions.insert(version) {
                 return Err(FlywayNaimngCheckerError::DuplicateVersion {
                     file: file_name.to_string(),
@@ -109,22 +176,125 @@ pub fn check_for_duplicate_versions(file_names: &[String]) -> Result<(), FlywayN
     Ok(())
 }
 
-pub fn check_for_skipped_versions(file_names: &[String]) -> Result<(), FlywayNaimngCheckerError> {
-    let mut versions: Vec<u32> = file_names
-        .iter()
-        .filter_map(|file_name| extract_version(file_name))
-        .collect();
+pub fn check_for_skipped_versions(
+    file_names: &[String],
+    config: &NamingConfig,
+) -> Result<(), FlywayNaimngCheckerError> {
+    let mut versions: Vec<MigrationVersion> = Vec::new();
+
+    for file_name in file_names {
+        if let Some(version) = extract_version(file_name, config)? {
+            versions.push(version);
+        }
+    }
 
     if versions.is_empty() {
         return Ok(());
     }
 
-    versions.sort_unstable();
+    versions.sort();
 
     for window in versions.windows(2) {
-        if window[1] != window[0] + 1 {
-            return Err(FlywayNaimngCheckerError::SkippedVersion {
-                file: format!("From V{} to V{}", window[0], window[1]),
+        let prev = &window[0];
+        let next = &window[1];
+
+        if prev.components.len() != next.components.len() {
+            continue;
+        }
+
+        let diff_index = prev
+            .components
+            .iter()
+            .zip(next.components.iter())
+            .position(|(a, b)| a != b);
+
+        if let Some(index) = diff_index {
+            if next.components[index] - prev.components[index] > 1 {
+                return Err(FlywayNaimngCheckerError::SkippedVersion {
+                    file: format!(
+                        "From {0}{1} to {0}{2}",
+                        config.version_prefix, prev, next
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Verify that every `U<version>` undo migration has a matching `V<version>`
+/// migration in the same file set, keyed on the parsed version rather than
+/// the raw file name.
+pub fn check_undo_pairs(
+    file_names: &[String],
+    config: &NamingConfig,
+) -> Result<(), FlywayNaimngCheckerError> {
+    let Some(undo_prefix) = config.undo_prefix else {
+        return Ok(());
+    };
+    let versioned_prefix = config.version_prefix;
+
+    let mut versioned = HashSet::new();
+
+    for file_name in file_names {
+        if file_name.starts_with(versioned_prefix) {
+            if let Some(version) = extract_version(file_name, config)? {
+                versioned.insert(version);
+            }
+        }
+    }
+
+    for file_name in file_names {
+        if file_name.starts_with(undo_prefix) {
+            let version = extract_version(file_name, config)?;
+            if version.is_none_or(|version| !versioned.contains(&version)) {
+                return Err(FlywayNaimngCheckerError::OrphanUndoMigration {
+                    file: file_name.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Verify that every repeatable migration carries a non-empty description
+/// and no version token, per Flyway's repeatable migration rules.
+pub fn check_repeatable(
+    file_names: &[String],
+    config: &NamingConfig,
+) -> Result<(), FlywayNaimngCheckerError> {
+    let Some(repeatable_prefix) = config.repeatable_prefix else {
+        return Ok(());
+    };
+
+    for file_name in file_names {
+        if !file_name.starts_with(repeatable_prefix) {
+            continue;
+        }
+
+        let parts: Vec<&str> = file_name.split(config.separator.as_str()).collect();
+        if parts.len() != 2 {
+            continue;
+        }
+
+        let description = match parts[1].rfind('.') {
+            Some(dot_index) => &parts[1][..dot_index],
+            None => parts[1],
+        };
+        if description.is_empty() {
+            return Err(FlywayNaimngCheckerError::RepeatableMissingDescription {
+                file: file_name.to_string(),
+            });
+        }
+
+        if parts[0][repeatable_prefix.len_utf8()..]
+            .chars()
+            .any(|c| c.is_ascii_digit())
+        {
+            return Err(FlywayNaimngCheckerError::RepeatableHasVersion {
+                file: file_name.to_string(),
             });
         }
     }
@@ -132,13 +302,108 @@ pub fn check_for_skipped_versions(file_names: &[String]) -> Result<(), FlywayNai
     Ok(())
 }
 
-fn extract_version(file_name: &str) -> Option<u32> {
-    let parts: Vec<&str> = file_name.split("__").collect();
+/// A Flyway migration version parsed into its ordered numeric components, e.g.
+/// `V1.2.3` becomes `[1, 2, 3]`. Comparison is component-wise left-to-right,
+/// treating a missing trailing component as zero, so `1.1` == `1.1.0` < `1.2`.
+#[derive(Debug, Clone)]
+pub struct MigrationVersion {
+    components: Vec<u64>,
+}
+
+impl MigrationVersion {
+    /// Components with trailing zeros trimmed, so `1.1` and `1.1.0` compare
+    /// and hash identically.
+    fn trimmed_components(&self) -> &[u64] {
+        let mut end = self.components.len();
+        while end > 0 && self.components[end - 1] == 0 {
+            end -= 1;
+        }
+        &self.components[..end]
+    }
+}
+
+impl PartialEq for MigrationVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed_components() == other.trimmed_components()
+    }
+}
+
+impl Eq for MigrationVersion {}
+
+impl Hash for MigrationVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.trimmed_components().hash(state);
+    }
+}
+
+impl MigrationVersion {
+    pub fn parse(version: &str, file_name: &str) -> Result<Self, FlywayNaimngCheckerError> {
+        let components = version
+            .split(['.', '_'])
+            .map(|segment| {
+                segment
+                    .parse::<u64>()
+                    .map_err(|_| FlywayNaimngCheckerError::FlywayNamingVersionComponentError {
+                        file: file_name.to_string(),
+                        segment: segment.to_string(),
+                    })
+            })
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        Ok(MigrationVersion { components })
+    }
+}
+
+impl fmt::Display for MigrationVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered = self
+            .components
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+        write!(f, "{}", rendered)
+    }
+}
+
+impl PartialOrd for MigrationVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MigrationVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.components.len().max(other.components.len());
+
+        for i in 0..len {
+            let a = self.components.get(i).copied().unwrap_or(0);
+            let b = other.components.get(i).copied().unwrap_or(0);
+
+            match a.cmp(&b) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+fn extract_version(
+    file_name: &str,
+    config: &NamingConfig,
+) -> Result<Option<MigrationVersion>, FlywayNaimngCheckerError> {
+    let parts: Vec<&str> = file_name.split(config.separator.as_str()).collect();
     if parts.len() != 2 {
-        return None;
+        return Ok(None);
     }
     let version_part = parts[0].trim_start_matches(|c: char| !c.is_ascii_digit());
-    version_part.parse::<u32>().ok()
+    if version_part.is_empty() {
+        return Ok(None);
+    }
+
+    MigrationVersion::parse(version_part, file_name).map(Some)
 }
 
 #[cfg(test)]
@@ -148,9 +413,10 @@ mod tests {
 
     #[test]
     fn test_valid_prefix() {
-        assert_eq!(is_valid_prefix("V1__some_migration.sql"), Ok(()));
+        let config = NamingConfig::default();
+        assert_eq!(is_valid_prefix("V1__some_migration.sql", &config), Ok(()));
         assert_eq!(
-            is_valid_prefix("X1__some_migration.sql"),
+            is_valid_prefix("X1__some_migration.sql", &config),
             Err(FlywayNaimngCheckerError::FlywayNamingPrefixError {
                 file: "X1__some_migration.sql".to_string(),
                 expected: "V".to_string(),
@@ -158,7 +424,7 @@ mod tests {
             })
         );
         assert_eq!(
-            is_valid_prefix("v1__some_migration.sql"),
+            is_valid_prefix("v1__some_migration.sql", &config),
             Err(FlywayNaimngCheckerError::FlywayNamingPrefixError {
                 file: "v1__some_migration.sql".to_string(),
                 expected: "V".to_string(),
@@ -169,9 +435,10 @@ mod tests {
 
     #[test]
     fn test_valid_suffix() {
-        assert_eq!(is_valid_suffix("V1__some_migration.sql"), Ok(()));
+        let config = NamingConfig::default();
+        assert_eq!(is_valid_suffix("V1__some_migration.sql", &config), Ok(()));
         assert_eq!(
-            is_valid_suffix("V1__some_migration.sqlx"),
+            is_valid_suffix("V1__some_migration.sqlx", &config),
             Err(FlywayNaimngCheckerError::FlywayNamingSufixError {
                 file: "V1__some_migration.sqlx".to_string(),
                 expected: ".sql".to_owned(),
@@ -182,20 +449,71 @@ mod tests {
 
     #[test]
     fn test_valid_separator() {
-        assert_eq!(is_valid_sepeartor("V1__some_migration.sql"), Ok(()));
+        let config = NamingConfig::default();
+        assert_eq!(is_valid_sepeartor("V1__some_migration.sql", &config), Ok(()));
         assert_eq!(
-            is_valid_sepeartor("V1_some_migration.sql"),
+            is_valid_sepeartor("V1_some_migration.sql", &config),
             Err(FlywayNaimngCheckerError::FlywayNamingSeparatorError {
                 file: "V1_some_migration.sql".to_string(),
             })
         );
     }
 
+    #[test]
+    fn test_naming_config_custom_scheme() {
+        let config = NamingConfig {
+            prefixes: vec!['M'],
+            separator: "--".to_string(),
+            suffixes: vec!["pgsql".to_string()],
+            version_prefix: 'M',
+            undo_prefix: None,
+            repeatable_prefix: None,
+        };
+
+        assert_eq!(is_valid_prefix("M1--some_migration.pgsql", &config), Ok(()));
+        assert_eq!(
+            is_valid_prefix("V1--some_migration.pgsql", &config),
+            Err(FlywayNaimngCheckerError::FlywayNamingPrefixError {
+                file: "V1--some_migration.pgsql".to_string(),
+                expected: "M".to_string(),
+                found: "V".to_string(),
+            })
+        );
+        assert_eq!(is_valid_sepeartor("M1--some_migration.pgsql", &config), Ok(()));
+        assert_eq!(is_valid_suffix("M1--some_migration.pgsql", &config), Ok(()));
+        assert_eq!(
+            is_valid_suffix("M1--some_migration.sql", &config),
+            Err(FlywayNaimngCheckerError::FlywayNamingSufixError {
+                file: "M1--some_migration.sql".to_string(),
+                expected: ".pgsql".to_string(),
+                found: "sql".to_string(),
+            })
+        );
+
+        // A single-prefix scheme has no undo/repeatable roles: the sole
+        // prefix must still behave as an ordinary versioned migration,
+        // not be silently treated as the repeatable one.
+        assert_eq!(is_valid_version("M1--some_migration.pgsql", &config), Ok(()));
+        assert_eq!(
+            is_valid_version("Mabc--desc.pgsql", &config),
+            Err(FlywayNaimngCheckerError::FlywayNamingVersionError {
+                file: "Mabc--desc.pgsql".to_string(),
+                prefix: "M".to_string(),
+                found: "a".to_string(),
+            })
+        );
+        assert_eq!(
+            check_repeatable(&["M1--some_migration.pgsql".to_string()], &config),
+            Ok(())
+        );
+    }
+
     #[test]
     fn test_valid_version() {
-        assert_eq!(is_valid_version("V1__some_migration.sql"), Ok(()));
+        let config = NamingConfig::default();
+        assert_eq!(is_valid_version("V1__some_migration.sql", &config), Ok(()));
         assert_eq!(
-            is_valid_version("X1__some_migration.sql"),
+            is_valid_version("X1__some_migration.sql", &config),
             Err(FlywayNaimngCheckerError::FlywayNamingPrefixError {
                 file: "X1__some_migration.sql".to_string(),
                 expected: "V".to_string(),
@@ -203,7 +521,7 @@ mod tests {
             })
         );
         assert_eq!(
-            is_valid_version("Vb__some_migration.sql"),
+            is_valid_version("Vb__some_migration.sql", &config),
             Err(FlywayNaimngCheckerError::FlywayNamingVersionError {
                 file: "Vb__some_migration.sql".to_string(),
                 prefix: "V".to_string(),
@@ -220,7 +538,7 @@ mod tests {
             "V1__duplicate.sql".to_string(),
         ];
         assert_eq!(
-            check_for_duplicate_versions(&files),
+            check_for_duplicate_versions(&files, &NamingConfig::default()),
             Err(FlywayNaimngCheckerError::DuplicateVersion {
                 file: "V1__duplicate.sql".to_string(),
             })
@@ -234,10 +552,157 @@ mod tests {
             "V3__add_table.sql".to_string(),
         ];
         assert_eq!(
-            check_for_skipped_versions(&files),
+            check_for_skipped_versions(&files, &NamingConfig::default()),
             Err(FlywayNaimngCheckerError::SkippedVersion {
                 file: "From V1 to V3".to_string(),
             })
         );
     }
+
+    #[test]
+    fn test_migration_version_ordering() {
+        let v1_1 = MigrationVersion::parse("1.1", "V1.1__x.sql").unwrap();
+        let v1_1_0 = MigrationVersion::parse("1.1.0", "V1.1.0__x.sql").unwrap();
+        let v1_2 = MigrationVersion::parse("1.2", "V1.2__x.sql").unwrap();
+
+        assert_eq!(v1_1, v1_1_0);
+        assert!(v1_1 < v1_2);
+    }
+
+    #[test]
+    fn test_migration_version_rejects_non_numeric_segment() {
+        assert_eq!(
+            MigrationVersion::parse("1.b.3", "V1.b.3__x.sql"),
+            Err(FlywayNaimngCheckerError::FlywayNamingVersionComponentError {
+                file: "V1.b.3__x.sql".to_string(),
+                segment: "b".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_duplicate_versions_with_dotted_components() {
+        let files = vec![
+            "V1.2__init.sql".to_string(),
+            "V1_2__duplicate.sql".to_string(),
+        ];
+        assert_eq!(
+            check_for_duplicate_versions(&files, &NamingConfig::default()),
+            Err(FlywayNaimngCheckerError::DuplicateVersion {
+                file: "V1_2__duplicate.sql".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_skipped_versions_with_dotted_components() {
+        let flagged = vec![
+            "V1.1__init.sql".to_string(),
+            "V1.3__add_table.sql".to_string(),
+        ];
+        assert_eq!(
+            check_for_skipped_versions(&flagged, &NamingConfig::default()),
+            Err(FlywayNaimngCheckerError::SkippedVersion {
+                file: "From V1.1 to V1.3".to_string(),
+            })
+        );
+
+        let not_flagged = vec![
+            "V1.9__init.sql".to_string(),
+            "V2.0__add_table.sql".to_string(),
+        ];
+        assert_eq!(
+            check_for_skipped_versions(&not_flagged, &NamingConfig::default()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_check_undo_pairs() {
+        let config = NamingConfig::default();
+        let files = vec![
+            "V1__init.sql".to_string(),
+            "U1__init.sql".to_string(),
+        ];
+        assert_eq!(check_undo_pairs(&files, &config), Ok(()));
+
+        let orphan = vec!["U2__missing.sql".to_string()];
+        assert_eq!(
+            check_undo_pairs(&orphan, &config),
+            Err(FlywayNaimngCheckerError::OrphanUndoMigration {
+                file: "U2__missing.sql".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_repeatable() {
+        let config = NamingConfig::default();
+        let files = vec!["R__add_view.sql".to_string()];
+        assert_eq!(check_repeatable(&files, &config), Ok(()));
+
+        assert_eq!(
+            check_repeatable(&["R__.sql".to_string()], &config),
+            Err(FlywayNaimngCheckerError::RepeatableMissingDescription {
+                file: "R__.sql".to_string(),
+            })
+        );
+
+        assert_eq!(
+            check_repeatable(&["R1__add_view.sql".to_string()], &config),
+            Err(FlywayNaimngCheckerError::RepeatableHasVersion {
+                file: "R1__add_view.sql".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_duplicate_versions_respects_custom_separator() {
+        let config = NamingConfig {
+            prefixes: vec!['M'],
+            separator: "--".to_string(),
+            suffixes: vec!["pgsql".to_string()],
+            version_prefix: 'M',
+            undo_prefix: None,
+            repeatable_prefix: None,
+        };
+
+        let files = vec![
+            "M1--init.pgsql".to_string(),
+            "M1--duplicate.pgsql".to_string(),
+        ];
+        assert_eq!(
+            check_for_duplicate_versions(&files, &config),
+            Err(FlywayNaimngCheckerError::DuplicateVersion {
+                file: "M1--duplicate.pgsql".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_undo_pairs_and_repeatable_respect_custom_separator() {
+        let config = NamingConfig {
+            prefixes: vec!['V', 'U', 'R'],
+            separator: "--".to_string(),
+            suffixes: vec!["sql".to_string()],
+            version_prefix: 'V',
+            undo_prefix: Some('U'),
+            repeatable_prefix: Some('R'),
+        };
+
+        let files = vec!["V1--init.sql".to_string(), "U1--init.sql".to_string()];
+        assert_eq!(check_undo_pairs(&files, &config), Ok(()));
+
+        assert_eq!(
+            check_undo_pairs(&["U2--missing.sql".to_string()], &config),
+            Err(FlywayNaimngCheckerError::OrphanUndoMigration {
+                file: "U2--missing.sql".to_string(),
+            })
+        );
+
+        assert_eq!(
+            check_repeatable(&["R--add_view.sql".to_string()], &config),
+            Ok(())
+        );
+    }
 }
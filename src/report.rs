@@ -0,0 +1,179 @@
+use crate::error::FlywayNaimngCheckerError;
+use crate::naming_checker::{
+    check_for_duplicate_versions, check_for_skipped_versions, check_repeatable, check_undo_pairs,
+    is_valid_prefix, is_valid_sepeartor, is_valid_suffix, is_valid_version, NamingConfig,
+};
+use std::fs;
+use std::path::Path;
+
+/// Summary of a full migrations directory scan: how many files passed or
+/// failed the per-file checks (prefix, suffix, separator, version), and every
+/// error raised along the way (one file can contribute more than one error).
+/// Errors from the cross-file checks (duplicate and skipped versions, undo
+/// pairing, repeatable rules) appear in `errors` only and do not change the
+/// `passed` / `failed` counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub passed: usize,
+    pub failed: usize,
+    pub errors: Vec<FlywayNaimngCheckerError>,
+}
+
+impl Report {
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Scan `path` for migration files matching `config` and run every validator
+/// against the full set, accumulating all failures instead of stopping at
+/// the first one.
+pub fn check_directory(
+    path: &Path,
+    config: &NamingConfig,
+) -> Result<Report, FlywayNaimngCheckerError> {
+    let entries = fs::read_dir(path).map_err(|source| FlywayNaimngCheckerError::DirectoryReadError {
+        path: path.display().to_string(),
+        message: source.to_string(),
+    })?;
+
+    let mut file_names = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|source| FlywayNaimngCheckerError::DirectoryReadError {
+            path: path.display().to_string(),
+            message: source.to_string(),
+        })?;
+
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        let matches_suffix = config
+            .suffixes
+            .iter()
+            .any(|suffix| file_name.ends_with(&format!(".{}", suffix)));
+        if matches_suffix {
+            file_names.push(file_name);
+        }
+    }
+
+    let mut errors = Vec::new();
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for file_name in &file_names {
+        let file_errors: Vec<FlywayNaimngCheckerError> = [
+            is_valid_prefix(file_name, config),
+            is_valid_suffix(file_name, config),
+            is_valid_sepeartor(file_name, config),
+            is_valid_version(file_name, config),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect();
+
+        if file_errors.is_empty() {
+            passed += 1;
+        } else {
+            failed += 1;
+        }
+
+        errors.extend(file_errors);
+    }
+
+    if let Err(err) = check_for_duplicate_versions(&file_names, config) {
+        errors.push(err);
+    }
+
+    if let Err(err) = check_for_skipped_versions(&file_names, config) {
+        errors.push(err);
+    }
+
+    if let Err(err) = check_undo_pairs(&file_names, config) {
+        errors.push(err);
+    }
+
+    if let Err(err) = check_repeatable(&file_names, config) {
+        errors.push(err);
+    }
+
+    Ok(Report {
+        passed,
+        failed,
+        errors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    fn temp_dir() -> std::path::PathBuf {
+        let id = COUNTER.fetch_add(1, Ordering::SeqCst);
+        let dir = std::env::temp_dir().join(format!("flyway-naming-checker-test-{}", id));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_check_directory_reports_every_failure() {
+        let dir = temp_dir();
+        fs::write(dir.join("V1__init.sql"), "").unwrap();
+        fs::write(dir.join("X2__bad_prefix.sql"), "").unwrap();
+        fs::write(dir.join("notes.txt"), "").unwrap();
+
+        let report = check_directory(&dir, &NamingConfig::default()).unwrap();
+
+        assert_eq!(report.passed, 1);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.errors.len(), 2);
+        assert!(!report.is_success());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_directory_all_valid() {
+        let dir = temp_dir();
+        fs::write(dir.join("V1__init.sql"), "").unwrap();
+        fs::write(dir.join("V2__add_table.sql"), "").unwrap();
+
+        let report = check_directory(&dir, &NamingConfig::default()).unwrap();
+
+        assert_eq!(report.passed, 2);
+        assert_eq!(report.failed, 0);
+        assert!(report.is_success());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_directory_flags_orphan_undo_migration() {
+        let dir = temp_dir();
+        fs::write(dir.join("U1__init.sql"), "").unwrap();
+
+        let report = check_directory(&dir, &NamingConfig::default()).unwrap();
+
+        assert!(report
+            .errors
+            .iter()
+            .any(|err| matches!(err, FlywayNaimngCheckerError::OrphanUndoMigration { .. })));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_directory_flags_repeatable_with_version() {
+        let dir = temp_dir();
+        fs::write(dir.join("R1__add_view.sql"), "").unwrap();
+
+        let report = check_directory(&dir, &NamingConfig::default()).unwrap();
+
+        assert!(report
+            .errors
+            .iter()
+            .any(|err| matches!(err, FlywayNaimngCheckerError::RepeatableHasVersion { .. })));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}